@@ -0,0 +1,125 @@
+/// An on-screen keyboard that drives the currently focused [`egui::TextEdit`]
+/// by synthesizing [`egui::Event::Text`]/[`egui::Event::Key`] and merging them
+/// into the *next* frame's [`egui::RawInput`], exactly as if they'd come from
+/// a physical keyboard.
+///
+/// Events can't simply be pushed into the current [`egui::Context`] input via
+/// `ctx.input_mut`: by the time a key button is clicked, this frame's widgets
+/// have already consumed their events, and `RawInput` replaces `events`
+/// wholesale at the start of the next frame anyway — so anything pushed here
+/// would be silently dropped. Instead, clicks are queued in `pending_events`
+/// and the host app must drain them into its `RawInput` before calling
+/// `ctx.run`, via [`Self::drain_pending_events`] — this is the same
+/// input-filter/injection hook pattern the app uses for its own raw input.
+///
+/// This is useful for touch/kiosk setups where there's no physical keyboard:
+/// tapping a key here has the exact same effect as typing it on the next
+/// frame.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct VirtualKeyboard {
+    text: String,
+
+    /// Synthesized events waiting to be merged into the next `RawInput` by
+    /// [`Self::drain_pending_events`]. Not persisted across sessions.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pending_events: Vec<egui::Event>,
+}
+
+impl Default for VirtualKeyboard {
+    fn default() -> Self {
+        Self {
+            text: "Tap a key below…".to_owned(),
+            pending_events: Vec::new(),
+        }
+    }
+}
+
+impl crate::Demo for VirtualKeyboard {
+    fn name(&self) -> &'static str {
+        "⌨ Virtual Keyboard"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable([true, false])
+            .default_width(320.0)
+            .show(ctx, |ui| {
+                use crate::View as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl crate::View for VirtualKeyboard {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Focus the text field, then tap keys on the on-screen keyboard:");
+        ui.add(egui::TextEdit::singleline(&mut self.text).hint_text("Type here"));
+
+        ui.add_space(8.0);
+
+        const ROWS: [&[char]; 3] = [
+            &['1', '2', '3', '4', '5', '6', '7', '8', '9', '0'],
+            &['q', 'w', 'e', 'r', 't', 'y', 'u', 'i', 'o', 'p'],
+            &['a', 's', 'd', 'f', 'g', 'h', 'j', 'k', 'l'],
+        ];
+
+        for row in ROWS {
+            ui.horizontal(|ui| {
+                for &key in row {
+                    if ui.button(key.to_string()).clicked() {
+                        self.queue_text(&key.to_string());
+                    }
+                }
+            });
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Space").clicked() {
+                self.queue_text(" ");
+            }
+            if ui.button("⌫ Backspace").clicked() {
+                self.queue_key(egui::Key::Backspace);
+            }
+            if ui.button("⏎ Enter").clicked() {
+                self.queue_key(egui::Key::Enter);
+            }
+        });
+
+        if !self.pending_events.is_empty() {
+            ui.ctx().request_repaint();
+        }
+    }
+}
+
+impl VirtualKeyboard {
+    /// Queue a synthesized text-input event for the next frame's `RawInput`.
+    fn queue_text(&mut self, text: &str) {
+        self.pending_events
+            .push(egui::Event::Text(text.to_owned()));
+    }
+
+    /// Queue a synthesized key-press event for the next frame's `RawInput`.
+    fn queue_key(&mut self, key: egui::Key) {
+        self.pending_events.push(egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::default(),
+        });
+    }
+
+    /// Drain the queued synthesized events so the host app can merge them
+    /// into the next [`egui::RawInput::events`] before calling `ctx.run`,
+    /// e.g.:
+    ///
+    /// ```ignore
+    /// raw_input.events.append(&mut virtual_keyboard.drain_pending_events());
+    /// let output = ctx.run(raw_input, |ctx| app.ui(ctx));
+    /// ```
+    pub fn drain_pending_events(&mut self) -> Vec<egui::Event> {
+        std::mem::take(&mut self.pending_events)
+    }
+}
@@ -1,8 +1,26 @@
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+enum Enum {
+    First,
+    Second,
+    Third,
+}
+
 /// Shows off one example of each major type of widget.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct WidgetGallery {
+    /// When false, [`Self::gallery_grid_contents`] is wrapped in a disabled
+    /// [`egui::UiBuilder`]. egui already propagates that down through
+    /// `Ui::is_enabled()`, so every widget added inside (and its AccessKit
+    /// node) comes out disabled without any extra handling here — see the
+    /// `disabled_gallery_marks_every_interactive_node_disabled` test below.
+    /// This does *not* apply to controls outside the grid, like the "Interactive"
+    /// checkbox itself, which must stay enabled so it can be toggled back on.
     enabled: bool,
+    /// When false, every widget in the grid is wrapped in an invisible
+    /// [`egui::UiBuilder`], which also hides the corresponding AccessKit
+    /// nodes from the accessibility tree.
     visible: bool,
     boolean: bool,
     opacity: f32,
@@ -10,6 +28,12 @@ pub struct WidgetGallery {
     string: String,
     color: egui::Color32,
     animate_progress_bar: bool,
+    selected: Enum,
+    combo_width: f32,
+
+    /// Scratch space for the "Presets" section; not itself persisted.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    preset_text: String,
 }
 
 impl Default for WidgetGallery {
@@ -23,6 +47,9 @@ impl Default for WidgetGallery {
             string: Default::default(),
             color: egui::Color32::LIGHT_BLUE.linear_multiply(0.5),
             animate_progress_bar: false,
+            selected: Enum::First,
+            combo_width: 100.0,
+            preset_text: Default::default(),
         }
     }
 }
@@ -56,6 +83,7 @@ impl crate::View for WidgetGallery {
 
         ui.scope_builder(ui_builder, |ui| {
             ui.multiply_opacity(self.opacity);
+            ui.spacing_mut().combo_width = self.combo_width;
 
             egui::Grid::new("my_grid")
                 .num_columns(2)
@@ -68,6 +96,71 @@ impl crate::View for WidgetGallery {
 
         ui.separator();
 
+        ui.collapsing("Theme", |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("☀ Light").clicked() {
+                    ui.ctx().set_visuals(egui::Visuals::light());
+                }
+                if ui.button("🌙 Dark").clicked() {
+                    ui.ctx().set_visuals(egui::Visuals::dark());
+                }
+            });
+
+            // Don't render widgets while holding the `style_mut` lock: the
+            // context is re-locked by every widget drawn, which deadlocks.
+            // Instead edit a local copy and write it back once we're done.
+            let mut visuals = ui.ctx().style().visuals.clone();
+            egui::Grid::new("theme_grid").num_columns(2).show(ui, |ui| {
+                ui.label("Panel fill");
+                ui.color_edit_button_srgba(&mut visuals.panel_fill);
+                ui.end_row();
+
+                ui.label("Window fill");
+                ui.color_edit_button_srgba(&mut visuals.window_fill);
+                ui.end_row();
+
+                ui.label("Window stroke");
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut visuals.window_stroke.width).speed(0.1));
+                    ui.color_edit_button_srgba(&mut visuals.window_stroke.color);
+                });
+                ui.end_row();
+            });
+            ui.ctx().set_visuals(visuals);
+        });
+
+        ui.separator();
+
+        #[cfg(feature = "serde")]
+        ui.collapsing("Presets", |ui| {
+            ui.label("Save the current gallery state to a string, or load one back:");
+            ui.add(
+                egui::TextEdit::multiline(&mut self.preset_text)
+                    .desired_rows(3)
+                    .code_editor(),
+            );
+            ui.horizontal(|ui| {
+                if ui.button("Save").clicked() {
+                    match ron::to_string(self) {
+                        Ok(preset) => self.preset_text = preset,
+                        Err(err) => self.preset_text = format!("Failed to save preset: {err}"),
+                    }
+                }
+                if ui.button("Load").clicked() {
+                    match ron::from_str::<Self>(&self.preset_text) {
+                        Ok(loaded) => {
+                            let preset_text = std::mem::take(&mut self.preset_text);
+                            *self = loaded;
+                            self.preset_text = preset_text;
+                        }
+                        Err(err) => self.preset_text = format!("Failed to load preset: {err}"),
+                    }
+                }
+            });
+        });
+
+        ui.separator();
+
         ui.horizontal(|ui| {
             ui.checkbox(&mut self.visible, "Visible")
                 .on_hover_text("Uncheck to hide all widgets.");
@@ -80,12 +173,24 @@ impl crate::View for WidgetGallery {
                         .range(0.0..=1.0),
                 ) | ui.label("Opacity"))
                 .on_hover_text("Reduce this value to make widgets semi-transparent");
+                (ui.add(
+                    egui::DragValue::new(&mut self.combo_width)
+                        .speed(1.0)
+                        .range(0.0..=400.0),
+                ) | ui.label("Combo width"))
+                .on_hover_text("Minimum width of the combo box above, via `Spacing::combo_width`");
             }
         });
     }
 }
 
 impl WidgetGallery {
+    /// A small "reset to default" button, meant to sit next to a single
+    /// field's widget in [`Self::gallery_grid_contents`].
+    fn reset_button(ui: &mut egui::Ui) -> egui::Response {
+        ui.small_button("⟲").on_hover_text("Reset to default")
+    }
+
     fn gallery_grid_contents(&mut self, ui: &mut egui::Ui) {
         let Self {
             enabled: _,
@@ -96,8 +201,13 @@ impl WidgetGallery {
             string,
             color,
             animate_progress_bar,
+            selected,
+            combo_width: _,
+            preset_text: _,
         } = self;
 
+        let defaults = Self::default();
+
         ui.label("Label");
         ui.label("Welcome to the widget gallery!");
         ui.end_row();
@@ -108,12 +218,31 @@ impl WidgetGallery {
         }
         ui.end_row();
 
+        ui.label("TextEdit");
+        ui.horizontal(|ui| {
+            ui.add(egui::TextEdit::singleline(string).hint_text("Write something here"));
+            if Self::reset_button(ui).clicked() {
+                *string = defaults.string.clone();
+            }
+        });
+        ui.end_row();
+
         ui.label("Checkbox");
-        ui.checkbox(boolean, "Checkbox");
+        ui.horizontal(|ui| {
+            ui.checkbox(boolean, "Checkbox");
+            if Self::reset_button(ui).clicked() {
+                *boolean = defaults.boolean;
+            }
+        });
         ui.end_row();
 
         ui.label("Slider");
-        ui.add(egui::Slider::new(scalar, 0.0..=360.0).suffix("°"));
+        ui.horizontal(|ui| {
+            ui.add(egui::Slider::new(scalar, 0.0..=360.0).suffix("°"));
+            if Self::reset_button(ui).clicked() {
+                *scalar = defaults.scalar;
+            }
+        });
         ui.end_row();
 
         ui.label("DragValue");
@@ -132,11 +261,99 @@ impl WidgetGallery {
         ui.end_row();
 
         ui.label("Color picker");
-        ui.color_edit_button_srgba(color);
+        ui.horizontal(|ui| {
+            ui.color_edit_button_srgba(color);
+            if Self::reset_button(ui).clicked() {
+                *color = defaults.color;
+            }
+        });
+        ui.end_row();
+
+        ui.label("ComboBox");
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("combo_box")
+                .selected_text(format!("{selected:?}"))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(selected, Enum::First, "First");
+                    ui.selectable_value(selected, Enum::Second, "Second");
+                    ui.selectable_value(selected, Enum::Third, "Third");
+                });
+            if Self::reset_button(ui).clicked() {
+                *selected = defaults.selected;
+            }
+        });
         ui.end_row();
 
         ui.label("Separator");
         ui.separator();
         ui.end_row();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "accesskit"))]
+mod tests {
+    use super::WidgetGallery;
+
+    /// Run one frame of just the (possibly disabled) grid — not the rest of
+    /// [`WidgetGallery::ui`], whose "Visible"/"Interactive" checkboxes and
+    /// Theme/Presets headers live outside the disabled scope and are
+    /// expected to stay enabled — and collect the resulting node tree.
+    fn run_grid(gallery: &mut WidgetGallery) -> accesskit::TreeUpdate {
+        let ctx = egui::Context::default();
+        ctx.enable_accesskit();
+        let raw_input = egui::RawInput::default();
+        let output = ctx.run(raw_input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                let mut ui_builder = egui::UiBuilder::new();
+                if !gallery.enabled {
+                    ui_builder = ui_builder.disabled();
+                }
+                ui.scope_builder(ui_builder, |ui| {
+                    egui::Grid::new("my_grid").num_columns(2).show(ui, |ui| {
+                        gallery.gallery_grid_contents(ui);
+                    });
+                });
+            });
+        });
+        output
+            .platform_output
+            .accesskit_update
+            .expect("accesskit_update should be populated when accesskit is enabled")
+    }
+
+    #[test]
+    fn disabled_gallery_marks_every_interactive_node_disabled() {
+        let mut gallery = WidgetGallery {
+            enabled: false,
+            ..WidgetGallery::default()
+        };
+
+        let update = run_grid(&mut gallery);
+
+        let interactive_roles = [
+            accesskit::Role::Button,
+            accesskit::Role::CheckBox,
+            accesskit::Role::Slider,
+            accesskit::Role::SpinButton,
+            accesskit::Role::TextInput,
+            accesskit::Role::ComboBox,
+            accesskit::Role::ColorWell,
+        ];
+
+        let mut found_interactive_node = false;
+        for (_, node) in &update.nodes {
+            if interactive_roles.contains(&node.role()) {
+                found_interactive_node = true;
+                assert!(
+                    node.is_disabled(),
+                    "node with role {:?} should be disabled when the gallery is disabled",
+                    node.role()
+                );
+            }
+        }
+        assert!(
+            found_interactive_node,
+            "expected to find at least one interactive node in the gallery"
+        );
+    }
+}